@@ -1,15 +1,43 @@
-// Tested with Rust 1.63.0 
+// Tested with Rust 1.63.0
 
-trait Operation {
-    fn calculate(&self, a: f64, b: f64) -> f64;
+#[derive(Debug)]
+enum OpError {
+    DivideByZero,
+}
+
+impl std::fmt::Display for OpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            OpError::DivideByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+trait Operation<N>
+where
+    N: std::ops::Add<Output = N> + std::ops::Mul<Output = N> + Copy + std::fmt::Display,
+{
+    fn calculate(&self, a: N, b: N) -> N;
     fn name(&self) -> String;
+
+    fn try_calculate(&self, a: N, b: N) -> Result<N, OpError> {
+        Ok(self.calculate(a, b))
+    }
+
+    fn identity(&self) -> f64 {
+        0.0
+    }
 }
 
 struct Add;
 struct Mul;
+struct Div;
 
-impl Operation for Add {
-    fn calculate(&self, a: f64, b: f64) -> f64 {
+impl<N> Operation<N> for Add
+where
+    N: std::ops::Add<Output = N> + std::ops::Mul<Output = N> + Copy + std::fmt::Display,
+{
+    fn calculate(&self, a: N, b: N) -> N {
         a + b
     }
 
@@ -18,30 +46,133 @@ impl Operation for Add {
     }
 }
 
-impl Operation for Mul {
-    fn calculate(&self, a: f64, b: f64) -> f64 {
+impl<N> Operation<N> for Mul
+where
+    N: std::ops::Add<Output = N> + std::ops::Mul<Output = N> + Copy + std::fmt::Display,
+{
+    fn calculate(&self, a: N, b: N) -> N {
         a * b
     }
 
     fn name(&self) -> String {
         String::from(" * ")
     }
+
+    fn identity(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Operation<f64> for Div {
+    fn calculate(&self, a: f64, b: f64) -> f64 {
+        a / b
+    }
+
+    fn name(&self) -> String {
+        String::from(" / ")
+    }
+
+    fn try_calculate(&self, a: f64, b: f64) -> Result<f64, OpError> {
+        if b == 0.0 {
+            Err(OpError::DivideByZero)
+        } else {
+            Ok(self.calculate(a, b))
+        }
+    }
 }
 
-fn do_the_math_dynamically(op: &dyn Operation, a: f64, b: f64) {
+fn do_the_math_dynamically<N>(op: &dyn Operation<N>, a: N, b: N)
+where
+    N: std::ops::Add<Output = N> + std::ops::Mul<Output = N> + Copy + std::fmt::Display,
+{
     let result = op.calculate(a, b);
     println!("Dynamic dispatch: {}{}{} = {}", a, op.name(), b, result);
 }
 
-fn do_the_math_statically<T: Operation> (op: &T, a: f64, b: f64) {
+fn do_the_math_statically<N, T: Operation<N>>(op: &T, a: N, b: N)
+where
+    N: std::ops::Add<Output = N> + std::ops::Mul<Output = N> + Copy + std::fmt::Display,
+{
     let result = op.calculate(a, b);
     println!("Static dispatch: {}{}{} = {}", a, op.name(), b, result);
 }
 
+fn do_the_math_checked(op: &dyn Operation<f64>, a: f64, b: f64) {
+    match op.try_calculate(a, b) {
+        Ok(result) => println!("Checked dispatch: {}{}{} = {}", a, op.name(), b, result),
+        Err(error) => println!("Checked dispatch: {}{}{} failed: {}", a, op.name(), b, error),
+    }
+}
+
+// A newtype wrapper that implements Rust's own operator-overloading traits,
+// rather than the crate's hand-rolled `Operation` trait.
+#[derive(Clone, Copy, Debug)]
+struct Scalar(f64);
+
+impl std::ops::Add for Scalar {
+    type Output = Scalar;
+
+    fn add(self, rhs: Scalar) -> Scalar {
+        Scalar(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, rhs: Scalar) -> Scalar {
+        Scalar(self.0 * rhs.0)
+    }
+}
+
+impl std::fmt::Display for Scalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// Selects one of the native `std::ops` traits at runtime instead of at
+// compile time, bridging the crate's dynamic/static dispatch demo to
+// Rust's own operator-overloading machinery.
+enum Op {
+    Add,
+    Mul,
+}
+
+fn apply<T>(op: Op, a: T, b: T) -> T
+where
+    T: std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+{
+    match op {
+        Op::Add => a + b,
+        Op::Mul => a * b,
+    }
+}
+
+fn fold_math(op: &dyn Operation<f64>, operands: &[f64]) -> f64 {
+    let mut acc = op.identity();
+    for &operand in operands {
+        acc = op.calculate(acc, operand);
+    }
+    acc
+}
+
 fn main() {
-    do_the_math_dynamically(&Add{}, 1.0, 2.0);
-    do_the_math_dynamically(&Mul{}, 4.0, 5.0);
+    do_the_math_dynamically(&Add{}, 1_i32, 2_i32);
+    do_the_math_dynamically(&Mul{}, 4_u64, 5_u64);
+    do_the_math_dynamically(&Add{}, 1.0_f64, 2.0_f64);
+
+    do_the_math_statically(&Add{}, 1_i32, 2_i32);
+    do_the_math_statically(&Mul{}, 4_u64, 5_u64);
+    do_the_math_statically(&Mul{}, 4.0_f64, 5.0_f64);
+
+    do_the_math_checked(&Div{}, 10.0, 2.0);
+    do_the_math_checked(&Div{}, 10.0, 0.0);
+
+    println!("Fold: {}", fold_math(&Add{}, &[1.0, 2.0, 3.0]));
+    println!("Fold: {}", fold_math(&Mul{}, &[1.0, 2.0, 3.0]));
 
-    do_the_math_statically(&Add{}, 1.0, 2.0);
-    do_the_math_statically(&Mul{}, 4.0, 5.0);
+    println!("Native op dispatch: {}", apply(Op::Add, Scalar(1.0), Scalar(2.0)));
+    println!("Native op dispatch: {}", apply(Op::Mul, Scalar(4.0), Scalar(5.0)));
+    println!("Native op dispatch: {}", apply(Op::Add, 4_i32, 5_i32));
 }
\ No newline at end of file